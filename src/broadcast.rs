@@ -0,0 +1,310 @@
+//! A multi-consumer channel: every [`Receiver`] gets its own copy of each
+//! sent value, modeled on tokio's ring-buffer broadcast channel.
+//!
+//! Unlike the point-to-point channel in the crate root, values here are not
+//! drained by whichever receiver happens to read them first — they sit in a
+//! fixed-size ring until every subscribed receiver has read them (or a slow
+//! receiver is lapped and skips ahead).
+
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// The value could not be sent because no [`Receiver`] is subscribed.
+///
+/// Carries the value back to the caller so it isn't silently lost.
+pub struct SendError<T>(pub T);
+
+impl<T> SendError<T> {
+    /// Unwrap the value that failed to send.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a broadcast channel with no receivers")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// Why [`Receiver::recv`] failed to produce a value.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecvError {
+    /// Every [`Sender`] has been dropped and there are no buffered values
+    /// left for this receiver to read.
+    Closed,
+    /// This receiver fell behind and the ring wrapped before it could read
+    /// some values. Carries how many were skipped; the cursor has already
+    /// been fast-forwarded to the oldest value still retained.
+    Lagged(u64),
+}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvError::Closed => write!(f, "receiving on a closed broadcast channel"),
+            RecvError::Lagged(n) => write!(f, "receiver lagged behind by {n} messages"),
+        }
+    }
+}
+
+impl std::error::Error for RecvError {}
+
+struct Slot<T> {
+    // `None` once every receiver that was around when it was sent has read
+    // it, or once it's been overwritten (`seq` below is the authority on
+    // whether a slot's current contents are actually this entry).
+    value: Option<T>,
+    seq: u64,
+    remaining: usize,
+}
+
+impl<T> Slot<T> {
+    fn empty() -> Self {
+        Slot {
+            value: None,
+            seq: 0,
+            remaining: 0,
+        }
+    }
+}
+
+struct Inner<T> {
+    // Fixed-size ring; slot `seq` lives at index `seq % buffer.len()`.
+    buffer: Vec<Slot<T>>,
+    /// Sequence number that will be assigned to the next sent value.
+    next_seq: u64,
+    senders: usize,
+    receivers: usize,
+}
+
+struct Shared<T> {
+    inner: Mutex<Inner<T>>,
+    available: Condvar,
+}
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.senders += 1;
+        drop(inner);
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.senders -= 1;
+        let was_last = inner.senders == 0;
+        drop(inner);
+        if was_last {
+            // Wake every receiver parked in recv() so they can notice the
+            // channel closed instead of waiting forever.
+            self.shared.available.notify_all();
+        }
+    }
+}
+
+impl<T: Clone> Sender<T> {
+    /// Send a value to every currently-subscribed receiver.
+    ///
+    /// Returns the number of receivers the value was delivered to, or hands
+    /// the value back in a [`SendError`] if none are subscribed.
+    pub fn send(&self, t: T) -> Result<usize, SendError<T>> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        if inner.receivers == 0 {
+            return Err(SendError(t));
+        }
+        let cap = inner.buffer.len();
+        let seq = inner.next_seq;
+        let receivers = inner.receivers;
+        let idx = (seq % cap as u64) as usize;
+        inner.buffer[idx] = Slot {
+            value: Some(t),
+            seq,
+            remaining: receivers,
+        };
+        inner.next_seq += 1;
+        drop(inner);
+        self.shared.available.notify_all();
+        Ok(receivers)
+    }
+
+    /// Create a new [`Receiver`] that starts reading from the current tail
+    /// of the ring — it will not see values sent before this call.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.receivers += 1;
+        let next_seq = inner.next_seq;
+        drop(inner);
+        Receiver {
+            shared: Arc::clone(&self.shared),
+            next_seq,
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    next_seq: u64,
+}
+
+impl<T: Clone> Receiver<T> {
+    pub fn recv(&mut self) -> Result<T, RecvError> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        loop {
+            let cap = inner.buffer.len() as u64;
+            let oldest = inner.next_seq.saturating_sub(cap);
+            if self.next_seq < oldest {
+                let skipped = oldest - self.next_seq;
+                self.next_seq = oldest;
+                return Err(RecvError::Lagged(skipped));
+            }
+            if self.next_seq == inner.next_seq {
+                if inner.senders == 0 {
+                    return Err(RecvError::Closed);
+                }
+                inner = self.shared.available.wait(inner).unwrap();
+                continue;
+            }
+            let idx = (self.next_seq % cap) as usize;
+            let slot = &mut inner.buffer[idx];
+            debug_assert_eq!(slot.seq, self.next_seq);
+            let value = slot
+                .value
+                .clone()
+                .expect("slot at an unread sequence number always holds a value");
+            self.next_seq += 1;
+            slot.remaining -= 1;
+            if slot.remaining == 0 {
+                slot.value = None;
+            }
+            return Ok(value);
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.receivers -= 1;
+        // Release this receiver's share of any slot it never got around to
+        // reading, so a lagging receiver that's dropped doesn't keep those
+        // slots pinned forever.
+        let cap = inner.buffer.len() as u64;
+        let oldest = inner.next_seq.saturating_sub(cap);
+        let start = self.next_seq.max(oldest);
+        let end = inner.next_seq;
+        for seq in start..end {
+            let idx = (seq % cap) as usize;
+            let slot = &mut inner.buffer[idx];
+            if slot.seq == seq && slot.value.is_some() {
+                slot.remaining = slot.remaining.saturating_sub(1);
+                if slot.remaining == 0 {
+                    slot.value = None;
+                }
+            }
+        }
+    }
+}
+
+/// Create a broadcast channel whose ring buffer retains up to `capacity`
+/// not-yet-fully-read values.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn broadcast<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(
+        capacity > 0,
+        "broadcast channel capacity must be greater than zero"
+    );
+    let buffer = (0..capacity).map(|_| Slot::empty()).collect();
+    let inner = Inner {
+        buffer,
+        next_seq: 0,
+        senders: 1,
+        receivers: 1,
+    };
+    let shared = Arc::new(Shared {
+        inner: Mutex::new(inner),
+        available: Condvar::new(),
+    });
+    (
+        Sender {
+            shared: Arc::clone(&shared),
+        },
+        Receiver {
+            shared,
+            next_seq: 0,
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{broadcast, RecvError};
+
+    #[test]
+    fn all_subscribers_see_every_value() {
+        let (tx, mut rx1) = broadcast(4);
+        let mut rx2 = tx.subscribe();
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_eq!(rx1.recv(), Ok(1));
+        assert_eq!(rx1.recv(), Ok(2));
+        assert_eq!(rx2.recv(), Ok(1));
+        assert_eq!(rx2.recv(), Ok(2));
+    }
+
+    #[test]
+    fn subscribe_only_sees_values_sent_after_it() {
+        let (tx, mut rx1) = broadcast(4);
+        tx.send(1).unwrap();
+        let mut rx2 = tx.subscribe();
+        tx.send(2).unwrap();
+        assert_eq!(rx1.recv(), Ok(1));
+        assert_eq!(rx1.recv(), Ok(2));
+        assert_eq!(rx2.recv(), Ok(2));
+    }
+
+    #[test]
+    fn lagging_receiver_reports_skipped_count() {
+        let (tx, mut rx) = broadcast(2);
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        tx.send(3).unwrap();
+        assert_eq!(rx.recv(), Err(RecvError::Lagged(1)));
+        assert_eq!(rx.recv(), Ok(2));
+        assert_eq!(rx.recv(), Ok(3));
+    }
+
+    #[test]
+    fn send_with_no_receivers_is_err() {
+        let (tx, rx) = broadcast(1);
+        drop(rx);
+        assert_eq!(tx.send(1).unwrap_err().into_inner(), 1);
+    }
+
+    #[test]
+    fn closed_after_all_senders_dropped() {
+        let (tx, mut rx) = broadcast::<()>(1);
+        drop(tx);
+        assert_eq!(rx.recv(), Err(RecvError::Closed));
+    }
+}
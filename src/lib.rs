@@ -1,8 +1,87 @@
 use std::{
     collections::VecDeque,
-    sync::{Arc, Condvar, Mutex},
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    task::{Context, Poll},
+    time::{Duration, Instant},
 };
 
+use crate::signal::{AsyncSignal, Signal, SyncSignal};
+
+pub mod broadcast;
+pub mod select;
+pub mod signal;
+
+/// The value could not be sent because every [`Receiver`] has been dropped.
+///
+/// Carries the value back to the caller so it isn't silently lost.
+pub struct SendError<T>(pub T);
+
+impl<T> SendError<T> {
+    /// Unwrap the value that failed to send.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a closed channel")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// Why [`Receiver::try_recv`] failed to produce a value.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// The queue is momentarily empty but at least one [`Sender`] remains.
+    Empty,
+    /// The queue is empty and every [`Sender`] has been dropped, so no more
+    /// values will ever arrive.
+    Disconnected,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "receiving on an empty channel"),
+            TryRecvError::Disconnected => write!(f, "receiving on a closed channel"),
+        }
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+/// Why [`Receiver::recv_timeout`] failed to produce a value.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// The deadline passed while the queue was still empty.
+    Timeout,
+    /// The queue is empty and every [`Sender`] has been dropped, so no more
+    /// values will ever arrive.
+    Disconnected,
+}
+
+impl fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvTimeoutError::Timeout => write!(f, "timed out waiting on channel"),
+            RecvTimeoutError::Disconnected => write!(f, "receiving on a closed channel"),
+        }
+    }
+}
+
+impl std::error::Error for RecvTimeoutError {}
+
 pub struct Sender<T> {
     shared: Arc<Shared<T>>,
 }
@@ -26,56 +105,280 @@ impl<T> Drop for Sender<T> {
         let mut inner = self.shared.inner.lock().unwrap();
         inner.sender -= 1;
         let was_last = inner.sender == 0;
+        // For one case:
+        // Receiver went to sleep
+        // Last sender is dropped
+        // Then the receiver will never be waken up
+        let waiters: Vec<Arc<dyn Signal>> = if was_last {
+            inner.waiters.drain(..).collect()
+        } else {
+            Vec::new()
+        };
         drop(inner);
-        if was_last {
-            // For one case:
-            // Receiver went to sleep
-            // Last sender is dropped
-            // Then the receiver will never be waken up
-            self.shared.available.notify_one();
+        for waiter in waiters {
+            waiter.fire();
         }
     }
 }
 
 impl<T> Sender<T> {
-    pub fn send(&mut self, t: T) {
+    pub fn send(&mut self, t: T) -> Result<(), SendError<T>> {
         let mut inner = self.shared.inner.lock().unwrap();
+        if inner.closed {
+            return Err(SendError(t));
+        }
         inner.queue.push_back(t);
         // This is CRUCIAL.
         // If the lock is not dropped, the receiver end will never acquire the lock when it is
         // waken up.
+        let waiter = inner.waiters.pop_front();
         drop(inner);
-        self.shared.available.notify_one();
+        if let Some(waiter) = waiter {
+            waiter.fire();
+        }
+        Ok(())
     }
 }
 
-pub struct Receiver<T> {
+/// The sending half of a [`sync_channel`], returned alongside a [`Receiver`].
+///
+/// Unlike [`Sender`], `send` applies backpressure: it blocks while the
+/// channel is full instead of growing the queue without bound.
+pub struct SyncSender<T> {
     shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for SyncSender<T> {
+    fn clone(&self) -> Self {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.sender += 1;
+        drop(inner);
+        SyncSender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for SyncSender<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.sender -= 1;
+        let was_last = inner.sender == 0;
+        let waiters: Vec<Arc<dyn Signal>> = if was_last {
+            inner.waiters.drain(..).collect()
+        } else {
+            Vec::new()
+        };
+        drop(inner);
+        for waiter in waiters {
+            waiter.fire();
+        }
+    }
+}
+
+impl<T> SyncSender<T> {
+    pub fn send(&mut self, t: T) -> Result<(), SendError<T>> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        let capacity = inner.capacity.expect("SyncSender always has a capacity");
+        loop {
+            if inner.closed {
+                return Err(SendError(t));
+            }
+            if capacity == 0 {
+                // Rendezvous mode: there is no room in the queue at all, so a
+                // send may only proceed once a receiver is actually parked
+                // waiting for it. This hands the value across directly
+                // instead of letting it sit unobserved in the queue.
+                //
+                // Note: this only serializes against one racing sender at a
+                // time via the held `inner` lock; it does not try to be fair
+                // across multiple `SyncSender` clones racing for the same
+                // waiting receiver.
+                if inner.waiters.is_empty() {
+                    inner = self.shared.space_available.wait(inner).unwrap();
+                    continue;
+                }
+            } else if inner.queue.len() >= capacity {
+                inner = self.shared.space_available.wait(inner).unwrap();
+                continue;
+            }
+            break;
+        }
+        inner.queue.push_back(t);
+        let waiter = inner.waiters.pop_front();
+        drop(inner);
+        if let Some(waiter) = waiter {
+            waiter.fire();
+        }
+        Ok(())
+    }
+}
+
+pub struct Receiver<T> {
+    pub(crate) shared: Arc<Shared<T>>,
     buffer: VecDeque<T>,
 }
 
 impl<T> Receiver<T> {
     pub fn recv(&mut self) -> Option<T> {
         if !self.buffer.is_empty() {
-            return self.buffer.pop_front();
+            let t = self.buffer.pop_front();
+            self.shared.space_available.notify_one();
+            return t;
         }
         let mut inner = self.shared.inner.lock().unwrap();
         loop {
             match inner.queue.pop_front() {
                 Some(t) => {
-                    if !inner.queue.is_empty() {
-                        std::mem::swap(&mut self.buffer, &mut inner.queue);
-                    }
+                    steal_rest_of_queue(&mut self.buffer, &mut inner);
+                    drop(inner);
+                    // Freed up a slot (or, in the rendezvous case, finished
+                    // taking delivery), so a blocked SyncSender may proceed.
+                    self.shared.space_available.notify_one();
                     return Some(t);
                 }
                 None if inner.sender < 1 => return None,
                 // if queue is empty, put this thread to sleep until it is waken up by the sender
-                // thread using Condvar
-                // Once it is awaken, it will acquire the MutexLock immediately.
-                // Before going to sleep, the lock **queue** has to be taken ownership
-                // otherwise the other thread will never acquire the lock
-                None => inner = self.shared.available.wait(inner).unwrap(),
+                // thread firing the signal we register in `park_for_signal`.
+                None => {
+                    inner = park_for_signal(&self.shared, inner, None);
+                }
+            }
+        }
+    }
+
+    /// Like [`recv`](Receiver::recv), but never blocks: if the queue is
+    /// momentarily empty it returns immediately instead of parking the
+    /// calling thread.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        if let Some(t) = self.buffer.pop_front() {
+            self.shared.space_available.notify_one();
+            return Ok(t);
+        }
+        let mut inner = self.shared.inner.lock().unwrap();
+        match inner.queue.pop_front() {
+            Some(t) => {
+                steal_rest_of_queue(&mut self.buffer, &mut inner);
+                drop(inner);
+                self.shared.space_available.notify_one();
+                Ok(t)
+            }
+            None if inner.sender < 1 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Like [`recv`](Receiver::recv), but gives up and returns
+    /// `Err(RecvTimeoutError::Timeout)` if no value shows up within `dur`.
+    pub fn recv_timeout(&mut self, dur: Duration) -> Result<T, RecvTimeoutError> {
+        if let Some(t) = self.buffer.pop_front() {
+            self.shared.space_available.notify_one();
+            return Ok(t);
+        }
+        let deadline = Instant::now() + dur;
+        let mut inner = self.shared.inner.lock().unwrap();
+        loop {
+            match inner.queue.pop_front() {
+                Some(t) => {
+                    steal_rest_of_queue(&mut self.buffer, &mut inner);
+                    drop(inner);
+                    self.shared.space_available.notify_one();
+                    return Ok(t);
+                }
+                None if inner.sender < 1 => return Err(RecvTimeoutError::Disconnected),
+                None => {
+                    let remaining = match deadline.checked_duration_since(Instant::now()) {
+                        Some(remaining) => remaining,
+                        None => return Err(RecvTimeoutError::Timeout),
+                    };
+                    inner = park_for_signal(&self.shared, inner, Some(remaining));
+                    // Re-check the queue on the next iteration regardless of
+                    // whether this was a spurious wakeup or a real timeout:
+                    // a value may have arrived right as the deadline passed.
+                }
+            }
+        }
+    }
+
+    /// Like [`recv`](Receiver::recv), but as a future instead of blocking the
+    /// calling thread: polling registers an [`AsyncSignal`] carrying the
+    /// current `Waker` so a later `send` can wake the executor instead of
+    /// `unpark`ing a thread.
+    pub fn recv_async(&mut self) -> RecvFuture<'_, T> {
+        RecvFuture {
+            receiver: self,
+            signal: None,
+        }
+    }
+}
+
+/// Future returned by [`Receiver::recv_async`].
+pub struct RecvFuture<'a, T> {
+    receiver: &'a mut Receiver<T>,
+    /// The [`AsyncSignal`] we're currently registered under in
+    /// `inner.waiters`, if any. Tracked so a re-poll can swap in a fresh
+    /// waker without leaving the old registration behind, and so `Drop` can
+    /// deregister it if this future is cancelled while still pending —
+    /// otherwise a `send` could later pop and fire a dead waker instead of
+    /// whoever is actually still waiting.
+    signal: Option<Arc<dyn Signal>>,
+}
+
+impl<T> Drop for RecvFuture<'_, T> {
+    fn drop(&mut self) {
+        if let Some(signal) = self.signal.take() {
+            let mut inner = self.receiver.shared.inner.lock().unwrap();
+            inner.waiters.retain(|w| !Arc::ptr_eq(w, &signal));
+        }
+    }
+}
+
+impl<T> Future for RecvFuture<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        if let Some(t) = this.receiver.buffer.pop_front() {
+            this.receiver.shared.space_available.notify_one();
+            return Poll::Ready(Some(t));
+        }
+        let mut inner = this.receiver.shared.inner.lock().unwrap();
+        match inner.queue.pop_front() {
+            Some(t) => {
+                steal_rest_of_queue(&mut this.receiver.buffer, &mut inner);
+                drop(inner);
+                this.receiver.shared.space_available.notify_one();
+                Poll::Ready(Some(t))
             }
+            None if inner.sender < 1 => Poll::Ready(None),
+            None => {
+                if let Some(old) = this.signal.take() {
+                    inner.waiters.retain(|w| !Arc::ptr_eq(w, &old));
+                }
+                let signal: Arc<dyn Signal> = Arc::new(AsyncSignal::new(cx.waker().clone()));
+                inner.waiters.push_back(Arc::clone(&signal));
+                this.signal = Some(signal);
+                this.receiver.shared.space_available.notify_one();
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.receivers -= 1;
+        let was_last = inner.receivers == 0;
+        if was_last {
+            inner.closed = true;
+        }
+        drop(inner);
+        if was_last {
+            // Wake any SyncSender blocked waiting for room so it can
+            // notice the channel closed and return a SendError instead of
+            // blocking forever.
+            self.shared.space_available.notify_all();
         }
     }
 }
@@ -88,24 +391,94 @@ impl<T> Iterator for Receiver<T> {
     }
 }
 
-struct Inner<T> {
+/// After popping one value, hand the rest of `inner.queue` to `buffer` so
+/// later `recv`s on an unbounded channel can skip the lock entirely.
+///
+/// Only done when the channel is unbounded: for a bounded (or rendezvous)
+/// channel, `inner.queue.len()` is what backpressure is measured against, so
+/// stealing it into a private buffer would let a sender refill the queue to
+/// `capacity` while those stolen items are still sitting unconsumed,
+/// defeating the whole point of the bound.
+fn steal_rest_of_queue<T>(buffer: &mut VecDeque<T>, inner: &mut Inner<T>) {
+    if inner.capacity.is_none() && !inner.queue.is_empty() {
+        std::mem::swap(buffer, &mut inner.queue);
+    }
+}
+
+pub(crate) struct Inner<T> {
     queue: VecDeque<T>,
     sender: usize,
+    /// `None` for an unbounded [`channel`]. `Some(0)` means rendezvous mode
+    /// (no slot is ever occupied; see [`SyncSender::send`]); `Some(n)` means
+    /// the queue may hold at most `n` items at once.
+    capacity: Option<usize>,
+    /// Receivers (blocking or async) currently parked waiting for a value,
+    /// in wakeup order. A `send` pops and fires the front one; a rendezvous
+    /// `SyncSender::send` checks whether this is non-empty to know a
+    /// consumer is actually ready before handing a value across. `select`
+    /// also registers here so it can be woken by any of several channels.
+    pub(crate) waiters: VecDeque<Arc<dyn Signal>>,
+    /// How many `Receiver`s are still alive.
+    receivers: usize,
+    /// Set once the last `Receiver` is dropped, so that `send` can report a
+    /// `SendError` instead of queueing a value nobody will ever read.
+    closed: bool,
 }
 
-struct Shared<T> {
-    inner: Mutex<Inner<T>>,
-    available: Condvar,
+pub(crate) struct Shared<T> {
+    pub(crate) inner: Mutex<Inner<T>>,
+    /// Signalled whenever a slot frees up: a receiver drains an item, or
+    /// (in rendezvous mode) a receiver starts waiting. Blocked `SyncSender`s
+    /// wait on this.
+    space_available: Condvar,
+}
+
+/// Register a [`SyncSignal`] for the calling thread, release `inner` and
+/// park on it (bounded by `timeout` if given), then re-acquire the lock and
+/// remove our own registration before returning.
+///
+/// Removing it here — rather than leaving it for a `send` to pop — is
+/// CRUCIAL: without it, a spurious wakeup (or a timeout) would leave a stale
+/// waiter in the queue, and a later `send` could pop and fire *that* instead
+/// of the real waiter, leaving whoever is actually parked asleep forever.
+///
+/// Registering before dropping the lock is also CRUCIAL: `park` consumes a
+/// permit set by an `unpark` that raced ahead of it, so it's fine even if
+/// `fire` runs before `wait` actually parks us.
+fn park_for_signal<'a, T>(
+    shared: &'a Shared<T>,
+    mut inner: MutexGuard<'a, Inner<T>>,
+    timeout: Option<Duration>,
+) -> MutexGuard<'a, Inner<T>> {
+    let signal: Arc<dyn Signal> = Arc::new(SyncSignal::current());
+    inner.waiters.push_back(Arc::clone(&signal));
+    // Tell any SyncSender blocked in rendezvous mode that a receiver is now
+    // parked and ready to take a value.
+    shared.space_available.notify_one();
+    drop(inner);
+    match timeout {
+        Some(remaining) => {
+            signal.wait_timeout(remaining);
+        }
+        None => signal.wait(),
+    }
+    let mut inner = shared.inner.lock().unwrap();
+    inner.waiters.retain(|w| !Arc::ptr_eq(w, &signal));
+    inner
 }
 
 pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     let inner = Inner {
         queue: VecDeque::default(),
         sender: 1,
+        capacity: None,
+        waiters: VecDeque::new(),
+        receivers: 1,
+        closed: false,
     };
     let shared = Shared {
         inner: Mutex::new(inner),
-        available: Condvar::new(),
+        space_available: Condvar::new(),
     };
     let shared = Arc::new(shared);
     (
@@ -119,14 +492,45 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     )
 }
 
+/// Create a bounded, synchronous channel: a [`SyncSender`] whose `send`
+/// blocks while the queue holds `capacity` items, paired with a [`Receiver`].
+///
+/// A `capacity` of `0` makes this a rendezvous channel: `send` blocks until a
+/// receiver is actually parked waiting for a value, then hands it across
+/// directly, so a value is never sitting unobserved in the queue.
+pub fn sync_channel<T>(capacity: usize) -> (SyncSender<T>, Receiver<T>) {
+    let inner = Inner {
+        queue: VecDeque::default(),
+        sender: 1,
+        capacity: Some(capacity),
+        waiters: VecDeque::new(),
+        receivers: 1,
+        closed: false,
+    };
+    let shared = Shared {
+        inner: Mutex::new(inner),
+        space_available: Condvar::new(),
+    };
+    let shared = Arc::new(shared);
+    (
+        SyncSender {
+            shared: shared.clone(),
+        },
+        Receiver {
+            shared: shared.clone(),
+            buffer: VecDeque::default(),
+        },
+    )
+}
+
 #[cfg(test)]
 mod test {
-    use super::channel;
+    use super::{channel, sync_channel};
 
     #[test]
     fn ping_pong() {
         let (mut sender, mut receiver) = channel();
-        sender.send(3);
+        sender.send(3).unwrap();
         assert_eq!(receiver.recv(), Some(3));
     }
 
@@ -140,11 +544,284 @@ mod test {
     #[test]
     fn iterate() {
         let (mut sender, mut receiver) = channel();
-        sender.send(1);
-        sender.send(2);
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
         assert_eq!(receiver.next(), Some(1));
         assert_eq!(receiver.next(), Some(2));
         drop(sender);
         assert_eq!(receiver.next(), None);
     }
+
+    #[test]
+    fn sync_send_recv() {
+        let (mut sender, mut receiver) = sync_channel(1);
+        sender.send(1).unwrap();
+        assert_eq!(receiver.recv(), Some(1));
+    }
+
+    #[test]
+    fn sync_send_blocks_when_full() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut sender, mut receiver) = sync_channel(1);
+        sender.send(1).unwrap();
+
+        let filled_second_slot = Arc::new(AtomicBool::new(false));
+        let filled_second_slot_clone = Arc::clone(&filled_second_slot);
+        let handle = thread::spawn(move || {
+            sender.send(2).unwrap();
+            filled_second_slot_clone.store(true, Ordering::SeqCst);
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(!filled_second_slot.load(Ordering::SeqCst));
+
+        assert_eq!(receiver.recv(), Some(1));
+        handle.join().unwrap();
+        assert!(filled_second_slot.load(Ordering::SeqCst));
+        assert_eq!(receiver.recv(), Some(2));
+    }
+
+    // Regression test for the unbounded "steal the rest of the queue into a
+    // private buffer" optimization leaking into bounded channels: `recv`
+    // used to steal unconditionally, draining `inner.queue` to empty while
+    // those stolen items sat unread in `buffer`, so a sender could refill
+    // the queue to `capacity` on top of them — up to ~2x capacity
+    // outstanding. `send` must now block on the fourth item with only one
+    // consumed.
+    #[test]
+    fn sync_send_backpressure_counts_items_stolen_into_buffer() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut sender, mut receiver) = sync_channel(2);
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+
+        // Take one item; the other stays in `inner.queue`, where it still
+        // counts against the capacity.
+        assert_eq!(receiver.recv(), Some(1));
+
+        let sent = Arc::new(AtomicUsize::new(0));
+        let sent_clone = Arc::clone(&sent);
+        let handle = thread::spawn(move || {
+            sender.send(3).unwrap();
+            sent_clone.store(1, Ordering::SeqCst);
+            sender.send(4).unwrap();
+            sent_clone.store(2, Ordering::SeqCst);
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+
+        assert_eq!(receiver.recv(), Some(2));
+        assert_eq!(receiver.recv(), Some(3));
+        handle.join().unwrap();
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+        assert_eq!(receiver.recv(), Some(4));
+    }
+
+    #[test]
+    fn rendezvous_hands_off_directly() {
+        use std::thread;
+
+        let (mut sender, mut receiver) = sync_channel(0);
+        let handle = thread::spawn(move || {
+            sender.send(42).unwrap();
+        });
+        assert_eq!(receiver.recv(), Some(42));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn send_after_receiver_dropped_is_err() {
+        let (mut sender, receiver) = channel();
+        drop(receiver);
+        assert_eq!(sender.send(1).unwrap_err().into_inner(), 1);
+    }
+
+    #[test]
+    fn try_recv_reports_empty_and_disconnected() {
+        use super::TryRecvError;
+
+        let (mut sender, mut receiver) = channel();
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+        sender.send(7).unwrap();
+        assert_eq!(receiver.try_recv(), Ok(7));
+        drop(sender);
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn recv_timeout_times_out_when_empty() {
+        use super::RecvTimeoutError;
+        use std::time::Duration;
+
+        let (_sender, mut receiver) = channel::<()>();
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(20)),
+            Err(RecvTimeoutError::Timeout)
+        );
+    }
+
+    #[test]
+    fn recv_timeout_returns_disconnected() {
+        use super::RecvTimeoutError;
+        use std::time::Duration;
+
+        let (sender, mut receiver) = channel::<()>();
+        drop(sender);
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(20)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+
+    #[test]
+    fn recv_timeout_returns_value_before_deadline() {
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut sender, mut receiver) = channel();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            sender.send(1).unwrap();
+        });
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(1));
+        handle.join().unwrap();
+    }
+
+    // Regression test for a stale-waiter leak: `recv_timeout` used to leave
+    // its `SyncSignal` registered in `inner.waiters` on the timeout path, so
+    // a later `send` could pop and fire a thread that already gave up on
+    // `recv_timeout` instead of whoever is actually parked. Simulate that by
+    // timing out on this thread, handing the receiver to another thread that
+    // parks in `recv`, and checking a single `send` actually wakes it.
+    #[test]
+    fn recv_timeout_does_not_leave_a_stale_waiter_for_recv() {
+        use super::RecvTimeoutError;
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut sender, mut receiver) = channel::<i32>();
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(20)),
+            Err(RecvTimeoutError::Timeout)
+        );
+
+        let handle = thread::spawn(move || receiver.recv());
+        thread::sleep(Duration::from_millis(20));
+        sender.send(42).unwrap();
+        assert_eq!(handle.join().unwrap(), Some(42));
+    }
+
+    /// Minimal single-future executor: parks the thread between polls and
+    /// relies on the `Waker` we hand out to `unpark` it again.
+    fn block_on<F: std::future::Future + Unpin>(mut fut: F) -> F::Output {
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+
+            fn wake_by_ref(self: &Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = std::pin::Pin::new(&mut fut);
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(value) => return value,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn recv_async_returns_sent_value() {
+        let (mut sender, mut receiver) = channel();
+        sender.send(5).unwrap();
+        assert_eq!(block_on(receiver.recv_async()), Some(5));
+    }
+
+    #[test]
+    fn recv_async_wakes_on_send_from_another_thread() {
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut sender, mut receiver) = channel();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            sender.send(9).unwrap();
+        });
+        assert_eq!(block_on(receiver.recv_async()), Some(9));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn recv_async_returns_none_when_closed() {
+        let (sender, mut receiver) = channel::<()>();
+        drop(sender);
+        assert_eq!(block_on(receiver.recv_async()), None);
+    }
+
+    // Regression test for a stale-waiter leak: `RecvFuture` used to push a
+    // fresh `AsyncSignal` on every poll-to-Pending without a `Drop` to remove
+    // it, so cancelling a future mid-poll (the way `select!` or a timeout
+    // wrapper would) left a dead waker registered. A later `send` could pop
+    // and fire that dead waker instead of a real, separately-parked receiver,
+    // leaving it asleep forever. Bound the wait on a timeout so a regression
+    // fails the test instead of hanging the suite.
+    #[test]
+    fn recv_async_drop_while_pending_does_not_leave_a_stale_waiter() {
+        use std::future::Future;
+        use std::sync::mpsc;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+        use std::thread;
+        use std::time::Duration;
+
+        struct NoopWaker;
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+            fn wake_by_ref(self: &Arc<Self>) {}
+        }
+
+        let (mut sender, mut receiver) = channel::<i32>();
+
+        {
+            let waker = Waker::from(Arc::new(NoopWaker));
+            let mut cx = Context::from_waker(&waker);
+            let mut fut = receiver.recv_async();
+            let fut = std::pin::Pin::new(&mut fut);
+            assert!(matches!(fut.poll(&mut cx), Poll::Pending));
+            // `fut` is dropped here, while still pending.
+        }
+
+        let (done_tx, done_rx) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            done_tx.send(receiver.recv()).unwrap();
+        });
+
+        thread::sleep(Duration::from_millis(20));
+        sender.send(7).unwrap();
+
+        let value = done_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("recv() should be woken by send, not left behind a stale waiter");
+        assert_eq!(value, Some(7));
+        handle.join().unwrap();
+    }
 }
@@ -0,0 +1,217 @@
+//! Block on several [`Receiver`]s at once and proceed with whichever has a
+//! value first, in the spirit of the classic `std::comm`/`chan` `select!`.
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::signal::Signal;
+use crate::{Receiver, TryRecvError};
+
+/// A signal shared by every channel registered with one [`Select`] call: any
+/// of them firing it wakes the single thread parked in [`Select::wait`].
+struct SelectSignal {
+    state: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl SelectSignal {
+    fn new() -> Self {
+        SelectSignal {
+            state: Arc::new((Mutex::new(false), Condvar::new())),
+        }
+    }
+}
+
+impl Signal for SelectSignal {
+    fn fire(&self) -> bool {
+        let (fired, condvar) = &*self.state;
+        let mut fired = fired.lock().unwrap();
+        let was_unfired = !*fired;
+        *fired = true;
+        condvar.notify_all();
+        was_unfired
+    }
+
+    fn wait(&self) {
+        let (fired, condvar) = &*self.state;
+        let mut fired = fired.lock().unwrap();
+        while !*fired {
+            fired = condvar.wait(fired).unwrap();
+        }
+    }
+
+    fn wait_timeout(&self, dur: Duration) -> bool {
+        let (fired, condvar) = &*self.state;
+        let mut fired = fired.lock().unwrap();
+        let deadline = Instant::now() + dur;
+        while !*fired {
+            let remaining = match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) => remaining,
+                None => return true,
+            };
+            let (guard, timeout_result) = condvar.wait_timeout(fired, remaining).unwrap();
+            fired = guard;
+            if timeout_result.timed_out() && !*fired {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Builder for waiting on the first ready of several [`Receiver`]s of the
+/// same type.
+///
+/// ```ignore
+/// let (index, value) = Select::new().recv(&mut r1).recv(&mut r2).wait().unwrap();
+/// ```
+pub struct Select<'r, T> {
+    receivers: Vec<&'r mut Receiver<T>>,
+}
+
+impl<'r, T> Default for Select<'r, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'r, T> Select<'r, T> {
+    pub fn new() -> Self {
+        Select {
+            receivers: Vec::new(),
+        }
+    }
+
+    /// Register a receiver to be selected over. Returns `self` so calls can
+    /// be chained.
+    pub fn recv(mut self, receiver: &'r mut Receiver<T>) -> Self {
+        self.receivers.push(receiver);
+        self
+    }
+
+    /// Block until one of the registered receivers has a value (or every
+    /// sender on every registered channel has hung up), then return the
+    /// index (into registration order) and value of the first one ready.
+    pub fn wait(mut self) -> Option<(usize, T)> {
+        loop {
+            // Register before polling: a send landing between a channel's
+            // try_recv and our registration would otherwise find `waiters`
+            // empty and fire nobody, deadlocking us. Registering first means
+            // such a send either lands in try_recv below (so we see the
+            // value directly) or fires our signal before we call wait()
+            // (which then returns immediately, since it latches `fired`).
+            let signal = Arc::new(SelectSignal::new());
+            for receiver in &self.receivers {
+                let mut inner = receiver.shared.inner.lock().unwrap();
+                inner.waiters.push_back(Arc::clone(&signal) as Arc<dyn Signal>);
+            }
+
+            let mut ready = None;
+            let mut any_open = false;
+            for (index, receiver) in self.receivers.iter_mut().enumerate() {
+                match receiver.try_recv() {
+                    Ok(value) => {
+                        ready = Some((index, value));
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => any_open = true,
+                    Err(TryRecvError::Disconnected) => {}
+                }
+            }
+
+            if ready.is_none() && any_open {
+                signal.wait();
+            }
+
+            // Unregister before returning or looping back, so a later send
+            // on a channel we're no longer interested in doesn't fire us
+            // again.
+            for receiver in &self.receivers {
+                let mut inner = receiver.shared.inner.lock().unwrap();
+                inner
+                    .waiters
+                    .retain(|w| !Arc::ptr_eq(w, &(Arc::clone(&signal) as Arc<dyn Signal>)));
+            }
+
+            if let Some(result) = ready {
+                return Some(result);
+            }
+            if !any_open {
+                return None;
+            }
+            // Woken (or the signal fired before we reached `wait`); re-poll.
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Select;
+    use crate::channel;
+
+    #[test]
+    fn selects_whichever_receiver_is_ready() {
+        let (mut tx1, mut rx1) = channel();
+        let (mut tx2, mut rx2) = channel();
+
+        tx2.send(2).unwrap();
+        let (index, value) = Select::new().recv(&mut rx1).recv(&mut rx2).wait().unwrap();
+        assert_eq!((index, value), (1, 2));
+
+        tx1.send(1).unwrap();
+        let (index, value) = Select::new().recv(&mut rx1).recv(&mut rx2).wait().unwrap();
+        assert_eq!((index, value), (0, 1));
+    }
+
+    #[test]
+    fn wakes_up_once_a_sender_on_either_channel_delivers() {
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut tx1, mut rx1) = channel();
+        let (_tx2, mut rx2) = channel();
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            tx1.send(7).unwrap();
+        });
+
+        let (index, value) = Select::new().recv(&mut rx1).recv(&mut rx2).wait().unwrap();
+        assert_eq!((index, value), (0, 7));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn returns_none_once_every_channel_is_disconnected() {
+        let (tx1, mut rx1) = channel::<()>();
+        let (tx2, mut rx2) = channel::<()>();
+        drop(tx1);
+        drop(tx2);
+
+        assert!(Select::new().recv(&mut rx1).recv(&mut rx2).wait().is_none());
+    }
+
+    // Regression test for a lost-wakeup deadlock: `wait` used to poll every
+    // receiver with `try_recv` and only register its signal afterwards, so a
+    // `send` landing in that gap was never observed. Hammering send/select
+    // across two threads for many rounds reliably hung within a couple of
+    // iterations under the old ordering; it must now complete every round.
+    #[test]
+    fn repeated_send_select_rounds_never_deadlock() {
+        use std::thread;
+
+        let (mut tx, mut rx) = channel();
+        let mut rx2 = channel::<i32>().1;
+
+        let handle = thread::spawn(move || {
+            for round in 0..200 {
+                tx.send(round).unwrap();
+            }
+        });
+
+        for round in 0..200 {
+            let (index, value) = Select::new().recv(&mut rx).recv(&mut rx2).wait().unwrap();
+            assert_eq!((index, value), (0, round));
+        }
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+//! Abstracts "wake whoever is waiting for the next value" so the same
+//! waiter queue can park a blocking thread or wake an async task, the way
+//! flume structures its wakeup hooks.
+
+use std::sync::Mutex;
+use std::task::Waker;
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// Something that can be parked on and later woken up.
+pub trait Signal: Send + Sync {
+    /// Wake whatever is registered to this signal. Returns `true` if this
+    /// call woke someone, `false` if the signal had already been fired.
+    /// [`SyncSignal`] and [`AsyncSignal`] cannot tell and always return
+    /// `true`.
+    fn fire(&self) -> bool;
+    /// Block the current thread until [`fire`](Signal::fire) is called.
+    ///
+    /// Only meaningful for signals backed by an actual OS thread; async
+    /// signals implement this by panicking, since an async task must never
+    /// block its executor.
+    fn wait(&self);
+    /// Block the current thread until [`fire`](Signal::fire) is called or
+    /// `dur` elapses. Returns `true` if it timed out.
+    fn wait_timeout(&self, dur: Duration) -> bool;
+}
+
+/// A [`Signal`] for a thread blocked on [`Receiver::recv`](crate::Receiver::recv)
+/// and friends. Backed by `std::thread::park`/`unpark`.
+pub struct SyncSignal {
+    thread: Thread,
+}
+
+impl SyncSignal {
+    /// Capture a signal for the calling thread.
+    pub fn current() -> Self {
+        SyncSignal {
+            thread: thread::current(),
+        }
+    }
+}
+
+impl Signal for SyncSignal {
+    fn fire(&self) -> bool {
+        self.thread.unpark();
+        true
+    }
+
+    fn wait(&self) {
+        thread::park();
+    }
+
+    fn wait_timeout(&self, dur: Duration) -> bool {
+        let started = Instant::now();
+        thread::park_timeout(dur);
+        started.elapsed() >= dur
+    }
+}
+
+/// A [`Signal`] for an async task polling
+/// [`Receiver::recv_async`](crate::Receiver::recv_async). Backed by a
+/// `std::task::Waker`.
+pub struct AsyncSignal {
+    waker: Mutex<Waker>,
+}
+
+impl AsyncSignal {
+    pub fn new(waker: Waker) -> Self {
+        AsyncSignal {
+            waker: Mutex::new(waker),
+        }
+    }
+}
+
+impl Signal for AsyncSignal {
+    fn fire(&self) -> bool {
+        self.waker.lock().unwrap().wake_by_ref();
+        true
+    }
+
+    fn wait(&self) {
+        panic!("AsyncSignal::wait called outside of an async task");
+    }
+
+    fn wait_timeout(&self, _dur: Duration) -> bool {
+        panic!("AsyncSignal::wait_timeout called outside of an async task");
+    }
+}